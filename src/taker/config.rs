@@ -3,7 +3,18 @@
 //!  Represents the configuration options for the Taker module, controlling behaviors
 //! such as refund locktime, connection attempts, sleep delays, and timeouts.
 
-use std::{io, path::PathBuf};
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, IsTerminal, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
 
 use crate::utill::{parse_field, parse_toml};
 /// Taker configuration with refund, connection, and sleep settings.
@@ -42,110 +53,695 @@ impl Default for TakerConfig {
 
 impl TakerConfig {
     pub fn new(file_path: Option<&PathBuf>) -> io::Result<Self> {
-        let default_config = Self::default();
+        let taker_config_section = Self::resolve_section(file_path)?;
+        Ok(Self::from_section(&taker_config_section))
+    }
+
+    /// Like [`TakerConfig::new`], but runs a strict validation pass that surfaces
+    /// every malformed field and semantic constraint violation at load time
+    /// instead of silently defaulting. Fields that are simply absent are still
+    /// filled from [`Default`]; only unparseable values, unknown keys, and broken
+    /// invariants are reported.
+    pub fn new_strict(file_path: Option<&PathBuf>) -> Result<Self, ConfigError> {
+        let taker_config_section = Self::resolve_section(file_path)?;
+        Self::from_section_strict(&taker_config_section)
+    }
 
-        let section = if let Some(path) = file_path {
-            if path.exists() {
+    /// Resolve the `[taker_config]` key/value section for the given path, applying
+    /// the same discovery and default-file creation rules as [`TakerConfig::new`].
+    fn resolve_section(file_path: Option<&PathBuf>) -> io::Result<HashMap<String, String>> {
+        let taker_config_section = if let Some(path) = file_path {
+            let section = if path.exists() {
                 parse_toml(path)?
             } else {
                 log::warn!(
                     "Taker config file not found, creating default config file at path: {}",
                     path.display()
                 );
-                create_default_taker_dirs(&path);
-                parse_toml(&path)?
-            }
+                create_default_taker_dirs(path);
+                parse_toml(path)?
+            };
+            section.get("taker_config").cloned().unwrap_or_default()
         } else {
-            let default_path = PathBuf::from("taker.toml");
-            if default_path.exists() {
-                parse_toml(&default_path)?
-            } else {
-                let default_taker_config_path = get_config_dir().join("taker.toml");
+            // No explicit path: merge every `taker.toml` discovered from the
+            // platform config directory up through the current directory.
+            let merged = Self::layered_section()?;
+            if merged.is_empty() {
+                let default_taker_config_path = platform_config_dir()
+                    .unwrap_or_else(|| PathBuf::from("."))
+                    .join("taker.toml");
                 if !default_taker_config_path.exists() {
-                    create_default_taker_dirs(&default_taker_config_path);
+                    // A fresh install with no config: guide the user through a
+                    // wizard when attached to a terminal, otherwise fall back to
+                    // writing the hardcoded defaults.
+                    if io::stdin().is_terminal() {
+                        let config = Self::wizard()?;
+                        config.save(&default_taker_config_path)?;
+                    } else {
+                        create_default_taker_dirs(&default_taker_config_path);
+                    }
                 }
                 log::warn!(
                     "Taker config file not found, creating a default config file at path: {}",
-                    default_path.display()
+                    default_taker_config_path.display()
                 );
                 parse_toml(&default_taker_config_path)?
+                    .get("taker_config")
+                    .cloned()
+                    .unwrap_or_default()
+            } else {
+                merged
             }
         };
 
-        let taker_config_section = section.get("taker_config").cloned().unwrap_or_default();
+        Ok(taker_config_section)
+    }
 
-        Ok(Self {
+    /// Merge `taker.toml` sections layer by layer, closest-wins.
+    ///
+    /// The platform config directory forms the base layer; on top of it every
+    /// ancestor of the current directory (farthest first) is applied, so a file
+    /// nearer the cwd overrides a farther one and any key absent everywhere is
+    /// left for [`from_section`](Self::from_section) to fill from [`Default`]. The
+    /// merge happens on the raw key/value pairs, so a partial file only overrides
+    /// the keys it actually sets.
+    ///
+    /// This deliberately broadens discovery beyond the old `./taker.toml` +
+    /// single config dir lookup, mirroring how formatter/linter tools layer a
+    /// project config over ancestor configs; it lets a repo-local `taker.toml`
+    /// override machine-wide defaults without duplicating every field.
+    fn layered_section() -> io::Result<HashMap<String, String>> {
+        let mut paths: Vec<PathBuf> = Vec::new();
+
+        // Base layer: platform config directory.
+        if let Some(base) = platform_config_dir() {
+            paths.push(base.join("taker.toml"));
+        }
+
+        // Ancestors from root down to cwd, so nearer directories are applied last.
+        if let Ok(cwd) = std::env::current_dir() {
+            let ancestors: Vec<PathBuf> = cwd.ancestors().map(Path::to_path_buf).collect();
+            for dir in ancestors.into_iter().rev() {
+                paths.push(dir.join("taker.toml"));
+            }
+        }
+
+        let mut merged: HashMap<String, String> = HashMap::new();
+        for path in paths {
+            if !path.exists() {
+                continue;
+            }
+            let section = parse_toml(&path)?;
+            if let Some(values) = section.get("taker_config") {
+                for (key, value) in values {
+                    merged.insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        Ok(merged)
+    }
+
+    /// Build a config from an already-parsed `[taker_config]` key/value section,
+    /// falling back to [`Default`] for every field that is absent or unparseable.
+    fn from_section(section: &HashMap<String, String>) -> Self {
+        let default_config = Self::default();
+
+        Self {
             refund_locktime: parse_field(
-                taker_config_section.get("refund_locktime"),
+                section.get("refund_locktime"),
                 default_config.refund_locktime,
             )
             .unwrap_or(default_config.refund_locktime),
             refund_locktime_step: parse_field(
-                taker_config_section.get("refund_locktime_step"),
+                section.get("refund_locktime_step"),
                 default_config.refund_locktime_step,
             )
             .unwrap_or(default_config.refund_locktime_step),
             first_connect_attempts: parse_field(
-                taker_config_section.get("first_connect_attempts"),
+                section.get("first_connect_attempts"),
                 default_config.first_connect_attempts,
             )
             .unwrap_or(default_config.first_connect_attempts),
             first_connect_sleep_delay_sec: parse_field(
-                taker_config_section.get("first_connect_sleep_delay_sec"),
+                section.get("first_connect_sleep_delay_sec"),
                 default_config.first_connect_sleep_delay_sec,
             )
             .unwrap_or(default_config.first_connect_sleep_delay_sec),
             first_connect_attempt_timeout_sec: parse_field(
-                taker_config_section.get("first_connect_attempt_timeout_sec"),
+                section.get("first_connect_attempt_timeout_sec"),
                 default_config.first_connect_attempt_timeout_sec,
             )
             .unwrap_or(default_config.first_connect_attempt_timeout_sec),
             reconnect_attempts: parse_field(
-                taker_config_section.get("reconnect_attempts"),
+                section.get("reconnect_attempts"),
                 default_config.reconnect_attempts,
             )
             .unwrap_or(default_config.reconnect_attempts),
             reconnect_short_sleep_delay: parse_field(
-                taker_config_section.get("reconnect_short_sleep_delay"),
+                section.get("reconnect_short_sleep_delay"),
                 default_config.reconnect_short_sleep_delay,
             )
             .unwrap_or(default_config.reconnect_short_sleep_delay),
             reconnect_long_sleep_delay: parse_field(
-                taker_config_section.get("reconnect_long_sleep_delay"),
+                section.get("reconnect_long_sleep_delay"),
                 default_config.reconnect_long_sleep_delay,
             )
             .unwrap_or(default_config.reconnect_long_sleep_delay),
             short_long_sleep_delay_transition: parse_field(
-                taker_config_section.get("short_long_sleep_delay_transition"),
+                section.get("short_long_sleep_delay_transition"),
                 default_config.short_long_sleep_delay_transition,
             )
             .unwrap_or(default_config.short_long_sleep_delay_transition),
             reconnect_attempt_timeout_sec: parse_field(
-                taker_config_section.get("reconnect_attempt_timeout_sec"),
+                section.get("reconnect_attempt_timeout_sec"),
                 default_config.reconnect_attempt_timeout_sec,
             )
             .unwrap_or(default_config.reconnect_attempt_timeout_sec),
+        }
+    }
+
+    /// Strictly build a config from a parsed `[taker_config]` section, collecting
+    /// every parse failure, unknown key, and semantic violation before returning.
+    fn from_section_strict(section: &HashMap<String, String>) -> Result<Self, ConfigError> {
+        let default_config = Self::default();
+        let mut errors: Vec<FieldError> = Vec::new();
+
+        // Unknown keys are almost always typos, which lenient defaulting hides.
+        for key in section.keys() {
+            if !FIELD_NAMES.contains(&key.as_str()) {
+                errors.push(FieldError {
+                    field: key.clone(),
+                    value: section.get(key).cloned().unwrap_or_default(),
+                    reason: "unknown config key".to_string(),
+                });
+            }
+        }
+
+        let config = Self {
+            refund_locktime: parse_strict(
+                section,
+                "refund_locktime",
+                default_config.refund_locktime,
+                &mut errors,
+            ),
+            refund_locktime_step: parse_strict(
+                section,
+                "refund_locktime_step",
+                default_config.refund_locktime_step,
+                &mut errors,
+            ),
+            first_connect_attempts: parse_strict(
+                section,
+                "first_connect_attempts",
+                default_config.first_connect_attempts,
+                &mut errors,
+            ),
+            first_connect_sleep_delay_sec: parse_strict(
+                section,
+                "first_connect_sleep_delay_sec",
+                default_config.first_connect_sleep_delay_sec,
+                &mut errors,
+            ),
+            first_connect_attempt_timeout_sec: parse_strict(
+                section,
+                "first_connect_attempt_timeout_sec",
+                default_config.first_connect_attempt_timeout_sec,
+                &mut errors,
+            ),
+            reconnect_attempts: parse_strict(
+                section,
+                "reconnect_attempts",
+                default_config.reconnect_attempts,
+                &mut errors,
+            ),
+            reconnect_short_sleep_delay: parse_strict(
+                section,
+                "reconnect_short_sleep_delay",
+                default_config.reconnect_short_sleep_delay,
+                &mut errors,
+            ),
+            reconnect_long_sleep_delay: parse_strict(
+                section,
+                "reconnect_long_sleep_delay",
+                default_config.reconnect_long_sleep_delay,
+                &mut errors,
+            ),
+            short_long_sleep_delay_transition: parse_strict(
+                section,
+                "short_long_sleep_delay_transition",
+                default_config.short_long_sleep_delay_transition,
+                &mut errors,
+            ),
+            reconnect_attempt_timeout_sec: parse_strict(
+                section,
+                "reconnect_attempt_timeout_sec",
+                default_config.reconnect_attempt_timeout_sec,
+                &mut errors,
+            ),
+        };
+
+        config.check_invariants(&mut errors);
+
+        if errors.is_empty() {
+            Ok(config)
+        } else {
+            Err(ConfigError::Invalid(errors))
+        }
+    }
+
+    /// Check the semantic invariants that must hold across fields, pushing a
+    /// [`FieldError`] for each violation rather than failing on the first.
+    fn check_invariants(&self, errors: &mut Vec<FieldError>) {
+        if self.refund_locktime_step > self.refund_locktime {
+            errors.push(FieldError {
+                field: "refund_locktime_step".to_string(),
+                value: self.refund_locktime_step.to_string(),
+                reason: format!("must not exceed refund_locktime ({})", self.refund_locktime),
+            });
+        }
+
+        if self.reconnect_short_sleep_delay > self.reconnect_long_sleep_delay {
+            errors.push(FieldError {
+                field: "reconnect_short_sleep_delay".to_string(),
+                value: self.reconnect_short_sleep_delay.to_string(),
+                reason: format!(
+                    "must not exceed reconnect_long_sleep_delay ({})",
+                    self.reconnect_long_sleep_delay
+                ),
+            });
+        }
+
+        if u64::from(self.short_long_sleep_delay_transition) > u64::from(self.reconnect_attempts) {
+            errors.push(FieldError {
+                field: "short_long_sleep_delay_transition".to_string(),
+                value: self.short_long_sleep_delay_transition.to_string(),
+                reason: format!(
+                    "must not exceed reconnect_attempts ({})",
+                    self.reconnect_attempts
+                ),
+            });
+        }
+
+        // Delays and timeouts of zero busy-loop or disable waiting entirely.
+        for (field, value) in [
+            (
+                "first_connect_sleep_delay_sec",
+                self.first_connect_sleep_delay_sec,
+            ),
+            (
+                "first_connect_attempt_timeout_sec",
+                self.first_connect_attempt_timeout_sec,
+            ),
+            ("reconnect_short_sleep_delay", self.reconnect_short_sleep_delay),
+            ("reconnect_long_sleep_delay", self.reconnect_long_sleep_delay),
+            (
+                "reconnect_attempt_timeout_sec",
+                self.reconnect_attempt_timeout_sec,
+            ),
+        ] {
+            if value == 0 {
+                errors.push(FieldError {
+                    field: field.to_string(),
+                    value: value.to_string(),
+                    reason: "must be non-zero".to_string(),
+                });
+            }
+        }
+    }
+
+    /// Parse a `taker.toml` at `path` into a [`TakerConfig`] using the same
+    /// lenient field-by-field defaulting as [`TakerConfig::new`].
+    fn load(path: &Path) -> io::Result<Self> {
+        let section = parse_toml(path)?;
+        let taker_config_section = section.get("taker_config").cloned().unwrap_or_default();
+        Ok(Self::from_section(&taker_config_section))
+    }
+
+    /// Watch `path` for changes and keep a shared config up to date.
+    ///
+    /// Returns a [`ConfigWatcher`] whose [`ConfigWatcher::config`] handle a
+    /// reconnection loop can read on each iteration to pick up the latest
+    /// settings, e.g.:
+    ///
+    /// ```ignore
+    /// let watcher = TakerConfig::watch(&path, Duration::from_secs(5))?;
+    /// let config = watcher.config();
+    /// loop {
+    ///     let attempts = config.read().unwrap().reconnect_attempts;
+    ///     // ... reconnect using the freshest value ...
+    /// }
+    /// ```
+    ///
+    /// A background thread polls the file's modification time every
+    /// `poll_interval` and, on change, re-parses via [`TakerConfig::load`]. If a
+    /// reload fails to parse, a warning is logged and the previous good config is
+    /// kept rather than crashing the session. Dropping (or calling
+    /// [`ConfigWatcher::stop`] on) the watcher signals the thread to exit and joins
+    /// it, so it is never left detached.
+    pub fn watch(path: &Path, poll_interval: Duration) -> io::Result<ConfigWatcher> {
+        let initial = Self::load(path)?;
+        let config = Arc::new(RwLock::new(initial));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let watch_path = path.to_path_buf();
+        let thread_config = Arc::clone(&config);
+        let thread_stop = Arc::clone(&stop);
+        let handle = std::thread::spawn(move || {
+            let mut last_modified = std::fs::metadata(&watch_path)
+                .and_then(|m| m.modified())
+                .ok();
+
+            while !thread_stop.load(Ordering::SeqCst) {
+                std::thread::sleep(poll_interval);
+                if thread_stop.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                let modified = match std::fs::metadata(&watch_path).and_then(|m| m.modified()) {
+                    Ok(modified) => modified,
+                    Err(e) => {
+                        log::warn!("Failed to stat taker config {}: {}", watch_path.display(), e);
+                        continue;
+                    }
+                };
+
+                if last_modified == Some(modified) {
+                    continue;
+                }
+                last_modified = Some(modified);
+
+                match Self::load(&watch_path) {
+                    Ok(new_config) => {
+                        log::info!("Reloaded taker config from {}", watch_path.display());
+                        if let Ok(mut guard) = thread_config.write() {
+                            *guard = new_config;
+                        }
+                    }
+                    Err(e) => log::warn!(
+                        "Failed to reload taker config from {}, keeping previous config: {}",
+                        watch_path.display(),
+                        e
+                    ),
+                }
+            }
+        });
+
+        Ok(ConfigWatcher {
+            config,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    /// Interactively build a [`TakerConfig`] by prompting for each field on stdin.
+    ///
+    /// Each field is printed as `"<name> [<default>]: "`; an empty response keeps
+    /// the shown default, otherwise the input is parsed into the field's type and
+    /// the prompt is repeated until a valid value is entered; at end of input the
+    /// default is kept. Intended for a guided
+    /// first-time setup in place of hand-editing the TOML file.
+    pub fn wizard() -> io::Result<Self> {
+        let stdin = io::stdin();
+        let mut reader = stdin.lock();
+        Self::wizard_from(&mut reader)
+    }
+
+    /// Core of [`TakerConfig::wizard`], reading from an arbitrary [`BufRead`] so the
+    /// prompting logic can be exercised with an in-memory reader in tests.
+    fn wizard_from<R: BufRead>(reader: &mut R) -> io::Result<Self> {
+        let default_config = Self::default();
+
+        Ok(Self {
+            refund_locktime: prompt_field(
+                reader,
+                "refund_locktime",
+                default_config.refund_locktime,
+            )?,
+            refund_locktime_step: prompt_field(
+                reader,
+                "refund_locktime_step",
+                default_config.refund_locktime_step,
+            )?,
+            first_connect_attempts: prompt_field(
+                reader,
+                "first_connect_attempts",
+                default_config.first_connect_attempts,
+            )?,
+            first_connect_sleep_delay_sec: prompt_field(
+                reader,
+                "first_connect_sleep_delay_sec",
+                default_config.first_connect_sleep_delay_sec,
+            )?,
+            first_connect_attempt_timeout_sec: prompt_field(
+                reader,
+                "first_connect_attempt_timeout_sec",
+                default_config.first_connect_attempt_timeout_sec,
+            )?,
+            reconnect_attempts: prompt_field(
+                reader,
+                "reconnect_attempts",
+                default_config.reconnect_attempts,
+            )?,
+            reconnect_short_sleep_delay: prompt_field(
+                reader,
+                "reconnect_short_sleep_delay",
+                default_config.reconnect_short_sleep_delay,
+            )?,
+            reconnect_long_sleep_delay: prompt_field(
+                reader,
+                "reconnect_long_sleep_delay",
+                default_config.reconnect_long_sleep_delay,
+            )?,
+            short_long_sleep_delay_transition: prompt_field(
+                reader,
+                "short_long_sleep_delay_transition",
+                default_config.short_long_sleep_delay_transition,
+            )?,
+            reconnect_attempt_timeout_sec: prompt_field(
+                reader,
+                "reconnect_attempt_timeout_sec",
+                default_config.reconnect_attempt_timeout_sec,
+            )?,
         })
     }
+
+    /// Serialize the config to `path` under a `[taker_config]` section.
+    ///
+    /// The file is produced from the struct itself, so it always stays in sync
+    /// with the in-memory config and can be re-loaded via [`TakerConfig::new`].
+    /// Lets callers persist a modified config (e.g. after the wizard or a runtime
+    /// override) back to disk.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        write_default_config(&path.to_path_buf(), self.to_toml_string())
+    }
+
+    /// Render the config as a `[taker_config]` TOML section straight from the
+    /// struct, so a persisted file always reflects the in-memory values.
+    fn to_toml_string(&self) -> String {
+        format!(
+            "[taker_config]\n\
+             refund_locktime = {}\n\
+             refund_locktime_step = {}\n\
+             first_connect_attempts = {}\n\
+             first_connect_sleep_delay_sec = {}\n\
+             first_connect_attempt_timeout_sec = {}\n\
+             reconnect_attempts = {}\n\
+             reconnect_short_sleep_delay = {}\n\
+             reconnect_long_sleep_delay = {}\n\
+             short_long_sleep_delay_transition = {}\n\
+             reconnect_attempt_timeout_sec = {}\n",
+            self.refund_locktime,
+            self.refund_locktime_step,
+            self.first_connect_attempts,
+            self.first_connect_sleep_delay_sec,
+            self.first_connect_attempt_timeout_sec,
+            self.reconnect_attempts,
+            self.reconnect_short_sleep_delay,
+            self.reconnect_long_sleep_delay,
+            self.short_long_sleep_delay_transition,
+            self.reconnect_attempt_timeout_sec,
+        )
+    }
+}
+
+/// Handle to a background config watcher spawned by [`TakerConfig::watch`].
+///
+/// Holds the shared, always-current config and owns the watcher thread so it can
+/// be stopped and joined instead of being left to run detached.
+pub struct ConfigWatcher {
+    config: Arc<RwLock<TakerConfig>>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ConfigWatcher {
+    /// Shared handle to the latest good config; read it on each reconnect iteration.
+    pub fn config(&self) -> Arc<RwLock<TakerConfig>> {
+        Arc::clone(&self.config)
+    }
+
+    /// Signal the watcher thread to stop and join it.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Prompt for a single config field, returning `default` on empty input or end of
+/// input and re-prompting until the response parses into `T`.
+fn prompt_field<R: BufRead, T>(reader: &mut R, name: &str, default: T) -> io::Result<T>
+where
+    T: FromStr + std::fmt::Display,
+{
+    loop {
+        print!("{} [{}]: ", name, default);
+        io::stdout().flush()?;
+
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            // EOF: keep the default rather than looping forever.
+            return Ok(default);
+        }
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            return Ok(default);
+        }
+
+        match trimmed.parse::<T>() {
+            Ok(value) => return Ok(value),
+            Err(_) => log::warn!("Invalid value for {}, please try again", name),
+        }
+    }
+}
+
+/// The set of keys recognized inside a `[taker_config]` section; anything else is
+/// treated as a typo in strict mode.
+const FIELD_NAMES: [&str; 10] = [
+    "refund_locktime",
+    "refund_locktime_step",
+    "first_connect_attempts",
+    "first_connect_sleep_delay_sec",
+    "first_connect_attempt_timeout_sec",
+    "reconnect_attempts",
+    "reconnect_short_sleep_delay",
+    "reconnect_long_sleep_delay",
+    "short_long_sleep_delay_transition",
+    "reconnect_attempt_timeout_sec",
+];
+
+/// A single field that failed strict validation, identified by name, the raw value
+/// that was read, and a human-readable reason.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub value: String,
+    pub reason: String,
+}
+
+/// Error returned when loading a config in strict mode fails.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The underlying TOML file could not be read or parsed.
+    Io(io::Error),
+    /// One or more fields were malformed or violated a semantic invariant.
+    Invalid(Vec<FieldError>),
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(err: io::Error) -> Self {
+        ConfigError::Io(err)
+    }
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(err) => write!(f, "failed to read taker config: {}", err),
+            ConfigError::Invalid(errors) => {
+                writeln!(f, "invalid taker config:")?;
+                for e in errors {
+                    writeln!(f, "  {} = {:?}: {}", e.field, e.value, e.reason)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Parse a single field strictly: an absent key keeps `default`, but a present
+/// value that fails to parse is recorded as a [`FieldError`] (the default is still
+/// returned so the remaining fields can be validated too).
+fn parse_strict<T>(
+    section: &HashMap<String, String>,
+    name: &str,
+    default: T,
+    errors: &mut Vec<FieldError>,
+) -> T
+where
+    T: FromStr,
+{
+    match section.get(name) {
+        None => default,
+        Some(raw) => match raw.parse::<T>() {
+            Ok(value) => value,
+            Err(_) => {
+                errors.push(FieldError {
+                    field: name.to_string(),
+                    value: raw.clone(),
+                    reason: format!("could not be parsed as {}", std::any::type_name::<T>()),
+                });
+                default
+            }
+        },
+    }
+}
+
+/// Cross-platform base config directory: a non-empty `$XDG_CONFIG_HOME` on any
+/// platform, otherwise `~/.config` on Linux, `~/Library/Application Support` on
+/// macOS, and `%APPDATA%` on Windows.
+fn platform_config_dir() -> Option<PathBuf> {
+    if let Some(xdg) = std::env::var_os("XDG_CONFIG_HOME") {
+        if !xdg.is_empty() {
+            return Some(PathBuf::from(xdg));
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    }
+    #[cfg(target_os = "macos")]
+    {
+        std::env::var_os("HOME").map(|h| PathBuf::from(h).join("Library/Application Support"))
+    }
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config"))
+    }
+    #[cfg(not(any(unix, target_os = "windows")))]
+    {
+        None
+    }
 }
 
 fn create_default_taker_dirs(target_path: &PathBuf) {
-    let config_string = String::from(
-        "\
-                        [taker_config]\n\
-                        refund_locktime = 48\n\
-                        refund_locktime_step = 48\n\
-                        first_connect_attempts = 5\n\
-                        first_connect_sleep_delay_sec = 1\n\
-                        first_connect_attempt_timeout_sec = 20\n\
-                        reconnect_attempts = 3200\n\
-                        reconnect_short_sleep_delay = 10\n\
-                        reconnect_long_sleep_delay = 60\n\
-                        short_long_sleep_delay_transition = 60\n\
-                        reconnect_attempt_timeout_sec = 300\n\
-                        ",
-    );
-    write_default_config(target_path, config_string).unwrap();
+    TakerConfig::default().save(target_path).unwrap();
 }
 
 #[cfg(test)]
@@ -155,7 +751,7 @@ mod tests {
     use super::*;
     use std::{
         fs::{self, File},
-        io::Write,
+        io::{Cursor, Write},
         path::PathBuf,
     };
 
@@ -239,6 +835,162 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_wizard_empty_input_keeps_defaults() {
+        // An all-empty session (one blank line per field) keeps every default.
+        let input = "\n".repeat(10);
+        let mut reader = Cursor::new(input.into_bytes());
+        let config = TakerConfig::wizard_from(&mut reader).unwrap();
+        assert_eq!(config, TakerConfig::default());
+    }
+
+    #[test]
+    fn test_wizard_reparses_on_bad_input() {
+        // The first field gets a garbage line then a valid one; the rest keep defaults.
+        let input = "not_a_number\n49\n".to_string() + &"\n".repeat(9);
+        let mut reader = Cursor::new(input.into_bytes());
+        let config = TakerConfig::wizard_from(&mut reader).unwrap();
+        assert_eq!(config.refund_locktime, 49);
+        assert_eq!(
+            TakerConfig {
+                refund_locktime: 48,
+                ..config
+            },
+            TakerConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_save_round_trip() {
+        let config_path = PathBuf::from("round_trip_taker_config.toml");
+        let original = TakerConfig::default();
+        original.save(&config_path).unwrap();
+        let reloaded = TakerConfig::new(Some(&config_path)).unwrap();
+        remove_temp_config(&config_path);
+        assert_eq!(reloaded, original);
+    }
+
+    #[test]
+    fn test_watch_reloads_on_change() {
+        use std::time::Duration;
+
+        let config_path = create_temp_config(
+            "[taker_config]\nreconnect_attempts = 3200\n",
+            "watch_taker_config.toml",
+        );
+
+        let watcher = TakerConfig::watch(&config_path, Duration::from_millis(50)).unwrap();
+        let config = watcher.config();
+        assert_eq!(config.read().unwrap().reconnect_attempts, 3200);
+
+        // Rewrite with a new value and wait for the watcher to pick it up.
+        fs::write(&config_path, "[taker_config]\nreconnect_attempts = 7\n").unwrap();
+        let mut reloaded = false;
+        for _ in 0..40 {
+            if config.read().unwrap().reconnect_attempts == 7 {
+                reloaded = true;
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+
+        watcher.stop();
+        remove_temp_config(&config_path);
+        assert!(reloaded, "watcher did not observe the config change");
+    }
+
+    #[test]
+    fn test_layered_closer_overrides_farther() {
+        // Base layer: a `taker.toml` in a temporary XDG config directory.
+        let xdg_dir = std::env::temp_dir().join("teleport_layered_test");
+        fs::create_dir_all(&xdg_dir).unwrap();
+        fs::write(
+            xdg_dir.join("taker.toml"),
+            "[taker_config]\nrefund_locktime = 10\nreconnect_attempts = 11\n",
+        )
+        .unwrap();
+        std::env::set_var("XDG_CONFIG_HOME", &xdg_dir);
+
+        // Nearer layer: `./taker.toml` overrides only the key it sets.
+        let local = PathBuf::from("taker.toml");
+        fs::write(&local, "[taker_config]\nrefund_locktime = 20\n").unwrap();
+
+        let config = TakerConfig::new(None).unwrap();
+
+        fs::remove_file(&local).unwrap();
+        fs::remove_file(xdg_dir.join("taker.toml")).unwrap();
+        std::env::remove_var("XDG_CONFIG_HOME");
+
+        // Closer file wins; a key only present in the base layer still applies.
+        assert_eq!(config.refund_locktime, 20);
+        assert_eq!(config.reconnect_attempts, 11);
+    }
+
+    #[test]
+    fn test_strict_valid_config() {
+        let contents = r#"
+        [taker_config]
+        refund_locktime = 48
+        refund_locktime_step = 48
+        "#;
+        let config_path = create_temp_config(contents, "strict_valid_taker_config.toml");
+        let config = TakerConfig::new_strict(Some(&config_path)).unwrap();
+        remove_temp_config(&config_path);
+        assert_eq!(config, TakerConfig::default());
+    }
+
+    #[test]
+    fn test_strict_unknown_key() {
+        let contents = r#"
+        [taker_config]
+        refund_loktime = 48
+        "#;
+        let config_path = create_temp_config(contents, "strict_unknown_taker_config.toml");
+        let err = TakerConfig::new_strict(Some(&config_path)).unwrap_err();
+        remove_temp_config(&config_path);
+        match err {
+            ConfigError::Invalid(errors) => {
+                assert!(errors.iter().any(|e| e.field == "refund_loktime"));
+            }
+            other => panic!("expected invalid config, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_strict_bad_type() {
+        let contents = r#"
+        [taker_config]
+        refund_locktime = "not_a_number"
+        "#;
+        let config_path = create_temp_config(contents, "strict_bad_type_taker_config.toml");
+        let err = TakerConfig::new_strict(Some(&config_path)).unwrap_err();
+        remove_temp_config(&config_path);
+        match err {
+            ConfigError::Invalid(errors) => {
+                assert!(errors.iter().any(|e| e.field == "refund_locktime"));
+            }
+            other => panic!("expected invalid config, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_strict_invariant_violation() {
+        let contents = r#"
+        [taker_config]
+        refund_locktime = 10
+        refund_locktime_step = 48
+        "#;
+        let config_path = create_temp_config(contents, "strict_invariant_taker_config.toml");
+        let err = TakerConfig::new_strict(Some(&config_path)).unwrap_err();
+        remove_temp_config(&config_path);
+        match err {
+            ConfigError::Invalid(errors) => {
+                assert!(errors.iter().any(|e| e.field == "refund_locktime_step"));
+            }
+            other => panic!("expected invalid config, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_missing_file() {
         let config_path = get_home_dir().join("taker.toml");